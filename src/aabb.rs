@@ -0,0 +1,49 @@
+use crate::ray::Ray;
+use crate::vec3::Point3;
+
+/// An axis-aligned bounding box, stored as its minimum and maximum corners.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Aabb {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl Aabb {
+    pub fn new(min: Point3, max: Point3) -> Aabb {
+        Aabb { min, max }
+    }
+
+    /// The classic slab test: shrink `[t_min, t_max]` against each pair of planes and
+    /// reject as soon as the interval collapses.
+    pub fn hit(&self, ray: &Ray, mut t_min: f64, mut t_max: f64) -> bool {
+        for axis in 0..3 {
+            let inv_d = 1.0 / ray.direction[axis];
+            let mut t0 = (self.min[axis] - ray.origin[axis]) * inv_d;
+            let mut t1 = (self.max[axis] - ray.origin[axis]) * inv_d;
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            t_min = t0.max(t_min);
+            t_max = t1.min(t_max);
+            if t_max <= t_min {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Returns the smallest box enclosing both `box0` and `box1`.
+pub fn surrounding_box(box0: &Aabb, box1: &Aabb) -> Aabb {
+    let small = Point3(
+        box0.min.x().min(box1.min.x()),
+        box0.min.y().min(box1.min.y()),
+        box0.min.z().min(box1.min.z()),
+    );
+    let big = Point3(
+        box0.max.x().max(box1.max.x()),
+        box0.max.y().max(box1.max.y()),
+        box0.max.z().max(box1.max.z()),
+    );
+    Aabb::new(small, big)
+}
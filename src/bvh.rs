@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::aabb::{surrounding_box, Aabb};
+use crate::hittable::{HitRecord, Hittable};
+use crate::hittable_list::HittableList;
+use crate::ray::Ray;
+
+/// A node in a bounding volume hierarchy. Implements [`Hittable`] so it can stand in
+/// wherever a [`HittableList`] is used, turning linear intersection tests into a
+/// logarithmic tree walk.
+pub struct BvhNode {
+    left: Arc<dyn Hittable + Send + Sync>,
+    right: Arc<dyn Hittable + Send + Sync>,
+    bbox: Aabb,
+}
+
+impl BvhNode {
+    /// Builds a hierarchy over the objects of `list`, consuming it.
+    pub fn from_list(list: HittableList) -> BvhNode {
+        let mut objects = list.objects;
+        let len = objects.len();
+        BvhNode::new(&mut objects, 0, len)
+    }
+
+    /// Builds a node over `objects[start..end]`.
+    pub fn new(
+        objects: &mut [Arc<dyn Hittable + Send + Sync>],
+        start: usize,
+        end: usize,
+    ) -> BvhNode {
+        let axis = rand::thread_rng().gen_range(0..3);
+        let comparator =
+            |a: &Arc<dyn Hittable + Send + Sync>, b: &Arc<dyn Hittable + Send + Sync>| {
+                box_compare(a, b, axis)
+            };
+
+        let span = end - start;
+        let (left, right): (
+            Arc<dyn Hittable + Send + Sync>,
+            Arc<dyn Hittable + Send + Sync>,
+        ) = match span {
+            1 => (Arc::clone(&objects[start]), Arc::clone(&objects[start])),
+            2 => {
+                if comparator(&objects[start], &objects[start + 1]) == Ordering::Greater {
+                    objects.swap(start, start + 1);
+                }
+                (
+                    Arc::clone(&objects[start]),
+                    Arc::clone(&objects[start + 1]),
+                )
+            }
+            _ => {
+                objects[start..end].sort_by(comparator);
+                let mid = start + span / 2;
+                (
+                    Arc::new(BvhNode::new(objects, start, mid)),
+                    Arc::new(BvhNode::new(objects, mid, end)),
+                )
+            }
+        };
+
+        let box_left = left
+            .bounding_box()
+            .expect("BVH construction requires bounded objects");
+        let box_right = right
+            .bounding_box()
+            .expect("BVH construction requires bounded objects");
+
+        BvhNode {
+            left,
+            right,
+            bbox: surrounding_box(&box_left, &box_right),
+        }
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        if !self.bbox.hit(ray, t_min, t_max) {
+            return false;
+        }
+
+        let hit_left = self.left.hit(ray, t_min, t_max, record);
+        let t_max = if hit_left { record.t } else { t_max };
+        let hit_right = self.right.hit(ray, t_min, t_max, record);
+
+        hit_left || hit_right
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.bbox)
+    }
+}
+
+/// Orders two objects by the minimum coordinate of their bounding boxes along `axis`.
+fn box_compare(
+    a: &Arc<dyn Hittable + Send + Sync>,
+    b: &Arc<dyn Hittable + Send + Sync>,
+    axis: usize,
+) -> Ordering {
+    let box_a = a.bounding_box().expect("BVH construction requires bounded objects");
+    let box_b = b.bounding_box().expect("BVH construction requires bounded objects");
+    box_a.min[axis]
+        .partial_cmp(&box_b.min[axis])
+        .unwrap_or(Ordering::Equal)
+}
@@ -0,0 +1,4 @@
+/// An RGB color. Shares [`Vec3`]'s layout and arithmetic so the two can be used
+/// interchangeably where it reads naturally (e.g. treating an albedo as a direction).
+/// Re-exported (not a type alias) so `Color(r, g, b)` works as a constructor.
+pub use crate::vec3::Vec3 as Color;
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::aabb::Aabb;
+use crate::color::Color;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::{Isotropic, Material};
+use crate::ray::Ray;
+use crate::texture::Texture;
+use crate::utils::INFINITY;
+use crate::vec3::Vec3;
+
+/// A volume of constant density bounded by a convex shape, used for smoke and fog. A ray
+/// passing through it scatters at a random depth drawn from the density.
+pub struct ConstantMedium {
+    boundary: Arc<dyn Hittable + Send + Sync>,
+    phase_function: Arc<dyn Material + Send + Sync>,
+    neg_inv_density: f64,
+}
+
+impl ConstantMedium {
+    pub fn new(
+        boundary: Arc<dyn Hittable + Send + Sync>,
+        density: f64,
+        color: &Color,
+    ) -> ConstantMedium {
+        ConstantMedium {
+            boundary,
+            phase_function: Arc::new(Isotropic::new(color)),
+            neg_inv_density: -1.0 / density,
+        }
+    }
+
+    pub fn from_texture(
+        boundary: Arc<dyn Hittable + Send + Sync>,
+        density: f64,
+        texture: Arc<dyn Texture + Send + Sync>,
+    ) -> ConstantMedium {
+        ConstantMedium {
+            boundary,
+            phase_function: Arc::new(Isotropic::from_texture(texture)),
+            neg_inv_density: -1.0 / density,
+        }
+    }
+}
+
+impl Hittable for ConstantMedium {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let mut rec1 = HitRecord::default();
+        let mut rec2 = HitRecord::default();
+
+        // Find where the ray enters and exits the boundary.
+        if !self.boundary.hit(ray, -INFINITY, INFINITY, &mut rec1) {
+            return false;
+        }
+        if !self.boundary.hit(ray, rec1.t + 0.0001, INFINITY, &mut rec2) {
+            return false;
+        }
+
+        rec1.t = rec1.t.max(t_min);
+        rec2.t = rec2.t.min(t_max);
+        if rec1.t >= rec2.t {
+            return false;
+        }
+        rec1.t = rec1.t.max(0.0);
+
+        let ray_length = ray.direction.magnitude();
+        let distance_inside = (rec2.t - rec1.t) * ray_length;
+        let hit_distance = self.neg_inv_density * rand::thread_rng().gen::<f64>().ln();
+        if hit_distance > distance_inside {
+            return false;
+        }
+
+        record.t = rec1.t + hit_distance / ray_length;
+        record.point = ray.at(record.t);
+        // The normal is arbitrary for a volumetric scatter event.
+        record.normal = Vec3(1.0, 0.0, 0.0);
+        record.front_face = true;
+        record.material = Some(Arc::clone(&self.phase_function));
+
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.boundary.bounding_box()
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{Point3, Vec3};
+
+/// Everything the renderer needs to know about a single ray/surface intersection.
+#[derive(Default, Clone)]
+pub struct HitRecord {
+    pub point: Point3,
+    pub normal: Vec3,
+    pub material: Option<Arc<dyn Material + Send + Sync>>,
+    pub t: f64,
+    pub u: f64,
+    pub v: f64,
+    pub front_face: bool,
+}
+
+impl HitRecord {
+    /// Orients `normal` so it always points against the incident ray, recording on which
+    /// side of the surface the ray struck in `front_face`.
+    pub fn set_face_normal(&mut self, ray: &Ray, outward_normal: Vec3) {
+        self.front_face = ray.direction.dot(&outward_normal) < 0.0;
+        self.normal = if self.front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+    }
+}
+
+/// Anything a ray can intersect.
+pub trait Hittable {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool;
+
+    /// The object's axis-aligned bounding box, or `None` if it cannot be bounded
+    /// (e.g. an infinite plane).
+    fn bounding_box(&self) -> Option<Aabb>;
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::aabb::{surrounding_box, Aabb};
+use crate::hittable::{HitRecord, Hittable};
+use crate::ray::Ray;
+
+/// A flat collection of hittable objects, tested linearly.
+#[derive(Default)]
+pub struct HittableList {
+    pub objects: Vec<Arc<dyn Hittable + Send + Sync>>,
+}
+
+impl HittableList {
+    pub fn new() -> HittableList {
+        HittableList::default()
+    }
+
+    pub fn clear(&mut self) {
+        self.objects.clear();
+    }
+
+    pub fn add<H: Hittable + Send + Sync + 'static>(&mut self, object: H) {
+        self.objects.push(Arc::new(object));
+    }
+}
+
+impl Hittable for HittableList {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let mut temp_record = HitRecord::default();
+        let mut hit_anything = false;
+        let mut closest_so_far = t_max;
+
+        for object in &self.objects {
+            if object.hit(ray, t_min, closest_so_far, &mut temp_record) {
+                hit_anything = true;
+                closest_so_far = temp_record.t;
+                *record = temp_record.clone();
+            }
+        }
+
+        hit_anything
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        if self.objects.is_empty() {
+            return None;
+        }
+
+        let mut output_box: Option<Aabb> = None;
+        for object in &self.objects {
+            let bb = object.bounding_box()?;
+            output_box = Some(match output_box {
+                Some(acc) => surrounding_box(&acc, &bb),
+                None => bb,
+            });
+        }
+
+        output_box
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::ray::Ray;
+use crate::utils::{degrees_to_radians, INFINITY};
+use crate::vec3::{Point3, Vec3};
+
+/// Wraps a hittable and offsets it by a fixed translation, without rebuilding it.
+pub struct Translate {
+    object: Arc<dyn Hittable + Send + Sync>,
+    offset: Vec3,
+}
+
+impl Translate {
+    pub fn new(object: Arc<dyn Hittable + Send + Sync>, offset: Vec3) -> Translate {
+        Translate { object, offset }
+    }
+}
+
+impl Hittable for Translate {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let moved = Ray::new(ray.origin - self.offset, ray.direction, ray.time);
+        if !self.object.hit(&moved, t_min, t_max, record) {
+            return false;
+        }
+
+        // A pure translation shifts the hit point but leaves the normal and the
+        // front/back orientation the child already determined untouched.
+        record.point += self.offset;
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.object
+            .bounding_box()
+            .map(|bb| Aabb::new(bb.min + self.offset, bb.max + self.offset))
+    }
+}
+
+/// Wraps a hittable and rotates it about the Y axis by a fixed angle.
+pub struct RotateY {
+    object: Arc<dyn Hittable + Send + Sync>,
+    sin_theta: f64,
+    cos_theta: f64,
+    bbox: Option<Aabb>,
+}
+
+impl RotateY {
+    pub fn new(object: Arc<dyn Hittable + Send + Sync>, angle: f64) -> RotateY {
+        let radians = degrees_to_radians(angle);
+        let sin_theta = radians.sin();
+        let cos_theta = radians.cos();
+
+        let bbox = object.bounding_box().map(|bb| {
+            let mut min = Point3(INFINITY, INFINITY, INFINITY);
+            let mut max = Point3(-INFINITY, -INFINITY, -INFINITY);
+
+            for i in 0..2 {
+                for j in 0..2 {
+                    for k in 0..2 {
+                        let x = i as f64 * bb.max.x() + (1 - i) as f64 * bb.min.x();
+                        let y = j as f64 * bb.max.y() + (1 - j) as f64 * bb.min.y();
+                        let z = k as f64 * bb.max.z() + (1 - k) as f64 * bb.min.z();
+
+                        let new_x = cos_theta * x + sin_theta * z;
+                        let new_z = -sin_theta * x + cos_theta * z;
+                        let tester = Vec3(new_x, y, new_z);
+
+                        for axis in 0..3 {
+                            min[axis] = min[axis].min(tester[axis]);
+                            max[axis] = max[axis].max(tester[axis]);
+                        }
+                    }
+                }
+            }
+
+            Aabb::new(min, max)
+        });
+
+        RotateY {
+            object,
+            sin_theta,
+            cos_theta,
+            bbox,
+        }
+    }
+}
+
+impl Hittable for RotateY {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        // Rotate the ray into the object's local frame.
+        let mut origin = ray.origin;
+        let mut direction = ray.direction;
+        origin[0] = self.cos_theta * ray.origin[0] - self.sin_theta * ray.origin[2];
+        origin[2] = self.sin_theta * ray.origin[0] + self.cos_theta * ray.origin[2];
+        direction[0] = self.cos_theta * ray.direction[0] - self.sin_theta * ray.direction[2];
+        direction[2] = self.sin_theta * ray.direction[0] + self.cos_theta * ray.direction[2];
+
+        let rotated = Ray::new(origin, direction, ray.time);
+        if !self.object.hit(&rotated, t_min, t_max, record) {
+            return false;
+        }
+
+        // Rotate the hit point and normal back into world space.
+        let mut point = record.point;
+        let mut normal = record.normal;
+        point[0] = self.cos_theta * record.point[0] + self.sin_theta * record.point[2];
+        point[2] = -self.sin_theta * record.point[0] + self.cos_theta * record.point[2];
+        normal[0] = self.cos_theta * record.normal[0] + self.sin_theta * record.normal[2];
+        normal[2] = -self.sin_theta * record.normal[0] + self.cos_theta * record.normal[2];
+
+        // Rotation preserves the sign of `direction · normal`, so the child's
+        // `front_face` stays valid; only the point and normal need rotating back.
+        record.point = point;
+        record.normal = normal;
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.bbox
+    }
+}
@@ -1,27 +1,43 @@
-use std::sync::{Arc, RwLock};
-use std::thread;
+// The renderer exposes a toolbox of primitives, materials and textures; the default demo
+// scene only exercises a subset of them, so unused public constructors are expected.
+#![allow(dead_code)]
+
+use std::sync::Arc;
 use std::time::Instant;
 
+use bvh::BvhNode;
 use camera::Camera;
 use color::Color;
 use hittable::{HitRecord, Hittable};
 use hittable_list::HittableList;
 use material::*;
-use rand::Rng;
+use output::{Output, Png, Ppm};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 use ray::Ray;
 use sphere::Sphere;
 use utils::INFINITY;
 use vec3::{Point3, Vec3};
 
-use crate::utils::clamp;
-
+mod aabb;
+mod bvh;
 mod camera;
 mod color;
+mod constant_medium;
 mod hittable;
 mod hittable_list;
+mod instance;
 mod material;
+mod moving_sphere;
+mod obj;
+mod output;
+mod perlin;
 mod ray;
+mod rect;
 mod sphere;
+mod texture;
+mod triangle;
 mod utils;
 mod vec3;
 
@@ -31,24 +47,26 @@ const IMAGE_HEIGHT: usize = (IMAGE_WIDTH as f64 / ASPECT_RATIO) as usize;
 const SAMPLES_PER_PIXEL: usize = 32;
 const MAX_DEPTH: usize = 50;
 
-fn ray_color(ray: &Ray, world: &Arc<RwLock<HittableList>>, depth: usize) -> Color {
+fn ray_color(ray: &Ray, background: Color, world: &dyn Hittable, depth: usize) -> Color {
     if depth == 0 {
         return Color(0.0, 0.0, 0.0);
     }
     let mut record = HitRecord::default();
-    if world.read().unwrap().hit(ray, 0.001, INFINITY, &mut record) {
-        let mut scattered = Ray::default();
-        let mut attenuation = Color::default();
-        if let Some(material) = &record.material {
-            if material.scatter(ray, &record, &mut attenuation, &mut scattered) {
-                return attenuation * ray_color(&scattered, world, depth - 1);
-            }
+    // Rays that escape the scene return the background color.
+    if !world.hit(ray, 0.001, INFINITY, &mut record) {
+        return background;
+    }
+
+    let mut scattered = Ray::default();
+    let mut attenuation = Color::default();
+    if let Some(material) = &record.material {
+        let emitted = material.emitted();
+        if material.scatter(ray, &record, &mut attenuation, &mut scattered) {
+            return emitted + attenuation * ray_color(&scattered, background, world, depth - 1);
         }
-        return Color::default();
+        return emitted;
     }
-    let unit_direction = ray.direction.normalized();
-    let t = 0.5 * (unit_direction.y() + 1.0);
-    (1.0 - t) * Color(1.0, 1.0, 1.0) + t * Color(0.5, 0.7, 1.0)
+    Color::default()
 }
 
 struct Timer(Instant);
@@ -118,59 +136,91 @@ fn main() {
 
     timer.log("Setting up Camera");
 
+    let background = Color(0.70, 0.80, 1.00);
+
     let look_from = Point3(13.0, 2.0, 3.0);
     let look_at = Point3(0.0, 0.0, 0.0);
 
-    let camera = Camera::new(look_from, look_at, Vec3::UP, 20.0, ASPECT_RATIO, 0.1, 10.0);
+    let camera = Camera::new(
+        look_from,
+        look_at,
+        Vec3::UP,
+        20.0,
+        ASPECT_RATIO,
+        0.1,
+        10.0,
+        0.0,
+        1.0,
+    );
 
     timer.log("Start Rendering Image");
 
-    // Render
-    let world = Arc::new(RwLock::new(world));
-
-    let mut offsets = [0f64; SAMPLES_PER_PIXEL * 2];
-
-    thread::scope(|s| {
-        let camera = &camera;
-        let mut handles = Vec::with_capacity(IMAGE_HEIGHT);
-        for y in (0..IMAGE_HEIGHT).rev() {
-            let world = Arc::clone(&world);
-            rng.fill(&mut offsets);
-
-            handles.push(s.spawn(move || {
-                let mut row = Vec::with_capacity(IMAGE_WIDTH);
-                for x in 0..IMAGE_WIDTH {
-                    let mut pixel_color = Color(0.0, 0.0, 0.0);
-                    for sample in 0..SAMPLES_PER_PIXEL {
-                        let u = (x as f64 + offsets[2 * sample]) / (IMAGE_WIDTH - 1) as f64;
-                        let v = (y as f64 + offsets[2 * sample + 1]) / (IMAGE_HEIGHT - 1) as f64;
-                        let ray = &camera.get_ray(u, v);
-                        pixel_color += ray_color(&ray, &world, MAX_DEPTH);
-                    }
-                    row.push(pixel_color);
+    // The scene never mutates during rendering, so hand it to the render tasks by shared
+    // reference. Wrapping it in a BVH also turns the linear `hit` into a tree walk.
+    let world = BvhNode::from_list(world);
+
+    let (format, output_path) = parse_args();
+    let mut output: Box<dyn Output> = match format.as_str() {
+        "png" => Box::new(Png::new(IMAGE_WIDTH, IMAGE_HEIGHT, SAMPLES_PER_PIXEL)),
+        _ => Box::new(Ppm::new(IMAGE_WIDTH, IMAGE_HEIGHT, SAMPLES_PER_PIXEL)),
+    };
+
+    // Render each row on Rayon's work-stealing pool. Each row owns its own RNG for the
+    // pixel jitter so the tasks never touch shared state, and `collect` gathers the rows
+    // back in order. Note that `Camera::get_ray` and the material `scatter` paths still
+    // draw from the thread-local RNG, so renders are not bit-for-bit reproducible.
+    let rows: Vec<Vec<Color>> = (0..IMAGE_HEIGHT)
+        .into_par_iter()
+        .map(|y| {
+            let mut rng = StdRng::seed_from_u64(y as u64);
+            let mut row = Vec::with_capacity(IMAGE_WIDTH);
+            for x in 0..IMAGE_WIDTH {
+                let mut pixel_color = Color(0.0, 0.0, 0.0);
+                for _ in 0..SAMPLES_PER_PIXEL {
+                    let u = (x as f64 + rng.gen::<f64>()) / (IMAGE_WIDTH - 1) as f64;
+                    let v = (y as f64 + rng.gen::<f64>()) / (IMAGE_HEIGHT - 1) as f64;
+                    let ray = camera.get_ray(u, v);
+                    pixel_color += ray_color(&ray, background, &world, MAX_DEPTH);
                 }
+                row.push(pixel_color);
+            }
+            row
+        })
+        .collect();
 
-                (y, row)
-            }));
+    for (y, row) in rows.into_iter().enumerate() {
+        for (x, color) in row.into_iter().enumerate() {
+            output.set_pixel(x, y, color);
         }
+    }
+
+    timer.log("Done Rendering Image\nWriting image data");
+    output.write(&output_path).expect("failed to write image");
+    timer.log("Done Writing Image Data");
+}
 
-        let scale = 1.0 / SAMPLES_PER_PIXEL as f64;
-        println!("P3\n{} {}\n255", IMAGE_WIDTH, IMAGE_HEIGHT);
-        for handle in handles {
-            let (_, data) = handle.join().unwrap();
-            for color in data {
-                let color = color * scale;
-                println!(
-                    "{} {} {}",
-                    (256.0 * clamp(color.0.sqrt(), 0.0, 0.999)) as u8,
-                    (256.0 * clamp(color.1.sqrt(), 0.0, 0.999)) as u8,
-                    (256.0 * clamp(color.2.sqrt(), 0.0, 0.999)) as u8
-                );
+/// Parses the optional `--format <ppm|png>` flag and output path from the command line,
+/// defaulting to a PPM written to `output.ppm`.
+fn parse_args() -> (String, String) {
+    let mut format = String::from("ppm");
+    let mut path: Option<String> = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                if let Some(value) = args.next() {
+                    format = value;
+                }
             }
+            other => path = Some(other.to_string()),
         }
+    }
+
+    let path = path.unwrap_or_else(|| match format.as_str() {
+        "png" => String::from("output.png"),
+        _ => String::from("output.ppm"),
     });
 
-    timer.log("Done Rendering Image\nPrinting out PPM data");
-    // image.print_ppm(SAMPLES_PER_PIXEL);
-    timer.log("Done Writing PPM Data");
+    (format, path)
 }
@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::color::Color;
+use crate::hittable::HitRecord;
+use crate::ray::Ray;
+use crate::texture::{SolidColor, Texture};
+use crate::vec3::{Point3, Vec3};
+
+/// A surface material: decides whether and how an incident ray scatters.
+pub trait Material {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool;
+
+    /// The light emitted by the surface. Non-emissive materials return black.
+    fn emitted(&self) -> Color {
+        Color(0.0, 0.0, 0.0)
+    }
+}
+
+/// An ideal diffuse (matte) surface, textured by its albedo.
+pub struct Lambertian {
+    pub albedo: Arc<dyn Texture + Send + Sync>,
+}
+
+impl Lambertian {
+    pub fn new(albedo: &Color) -> Lambertian {
+        Lambertian {
+            albedo: Arc::new(SolidColor::new(albedo)),
+        }
+    }
+
+    /// Builds a Lambertian backed by an arbitrary texture.
+    pub fn from_texture(albedo: Arc<dyn Texture + Send + Sync>) -> Lambertian {
+        Lambertian { albedo }
+    }
+}
+
+impl Material for Lambertian {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool {
+        let mut scatter_direction = record.normal + Vec3::random_unit_vector();
+
+        // Catch degenerate scatter directions that would yield NaNs later.
+        if scatter_direction.near_zero() {
+            scatter_direction = record.normal;
+        }
+
+        *scattered = Ray::new(record.point, scatter_direction, ray_in.time);
+        *attenuation = self.albedo.value(record.u, record.v, &record.point);
+        true
+    }
+}
+
+/// A reflective metal surface with optional fuzz.
+pub struct Metal {
+    pub albedo: Color,
+    pub fuzz: f64,
+}
+
+impl Metal {
+    pub fn new(albedo: &Color, fuzz: f64) -> Metal {
+        Metal {
+            albedo: *albedo,
+            fuzz: fuzz.min(1.0),
+        }
+    }
+}
+
+impl Material for Metal {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool {
+        let reflected = ray_in.direction.normalized().reflect(&record.normal);
+        *scattered = Ray::new(
+            record.point,
+            reflected + self.fuzz * Vec3::random_in_unit_sphere(),
+            ray_in.time,
+        );
+        *attenuation = self.albedo;
+        scattered.direction.dot(&record.normal) > 0.0
+    }
+}
+
+/// An isotropic phase function: scatters incoming rays in a uniformly random direction.
+/// Used as the scattering material for volumes such as smoke and fog.
+pub struct Isotropic {
+    pub albedo: Arc<dyn Texture + Send + Sync>,
+}
+
+impl Isotropic {
+    pub fn new(albedo: &Color) -> Isotropic {
+        Isotropic {
+            albedo: Arc::new(SolidColor::new(albedo)),
+        }
+    }
+
+    pub fn from_texture(albedo: Arc<dyn Texture + Send + Sync>) -> Isotropic {
+        Isotropic { albedo }
+    }
+}
+
+impl Material for Isotropic {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool {
+        *scattered = Ray::new(record.point, Vec3::random_in_unit_sphere(), ray_in.time);
+        *attenuation = self.albedo.value(record.u, record.v, &record.point);
+        true
+    }
+}
+
+/// A surface that emits light and never scatters, turning an object into a light source.
+pub struct DiffuseLight {
+    pub emit: Arc<dyn Texture + Send + Sync>,
+}
+
+impl DiffuseLight {
+    pub fn new(emit: &Color) -> DiffuseLight {
+        DiffuseLight {
+            emit: Arc::new(SolidColor::new(emit)),
+        }
+    }
+
+    /// Builds an emitter backed by an arbitrary texture.
+    pub fn from_texture(emit: Arc<dyn Texture + Send + Sync>) -> DiffuseLight {
+        DiffuseLight { emit }
+    }
+}
+
+impl Material for DiffuseLight {
+    fn scatter(
+        &self,
+        _ray_in: &Ray,
+        _record: &HitRecord,
+        _attenuation: &mut Color,
+        _scattered: &mut Ray,
+    ) -> bool {
+        false
+    }
+
+    fn emitted(&self) -> Color {
+        self.emit.value(0.0, 0.0, &Point3(0.0, 0.0, 0.0))
+    }
+}
+
+/// A dielectric (glass-like) surface with a given index of refraction.
+pub struct Dielectric {
+    pub index_of_refraction: f64,
+}
+
+impl Dielectric {
+    pub fn new(index_of_refraction: f64) -> Dielectric {
+        Dielectric {
+            index_of_refraction,
+        }
+    }
+
+    /// Schlick's approximation for the reflectance of a dielectric boundary.
+    fn reflectance(cosine: f64, ref_idx: f64) -> f64 {
+        let r0 = ((1.0 - ref_idx) / (1.0 + ref_idx)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+}
+
+impl Material for Dielectric {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool {
+        *attenuation = Color(1.0, 1.0, 1.0);
+        let refraction_ratio = if record.front_face {
+            1.0 / self.index_of_refraction
+        } else {
+            self.index_of_refraction
+        };
+
+        let unit_direction = ray_in.direction.normalized();
+        let cos_theta = (-unit_direction).dot(&record.normal).min(1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+        let cannot_refract = refraction_ratio * sin_theta > 1.0;
+        let direction = if cannot_refract
+            || Dielectric::reflectance(cos_theta, refraction_ratio)
+                > rand::thread_rng().gen::<f64>()
+        {
+            unit_direction.reflect(&record.normal)
+        } else {
+            unit_direction.refract(&record.normal, refraction_ratio)
+        };
+
+        *scattered = Ray::new(record.point, direction, ray_in.time);
+        true
+    }
+}
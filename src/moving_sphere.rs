@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::aabb::{surrounding_box, Aabb};
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{Point3, Vec3};
+
+/// A sphere whose center moves linearly between two positions over the shutter interval,
+/// producing motion blur when sampled across ray times.
+pub struct MovingSphere {
+    pub center0: Point3,
+    pub center1: Point3,
+    pub time0: f64,
+    pub time1: f64,
+    pub radius: f64,
+    pub material: Arc<dyn Material + Send + Sync>,
+}
+
+impl MovingSphere {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        center0: Point3,
+        center1: Point3,
+        time0: f64,
+        time1: f64,
+        radius: f64,
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> MovingSphere {
+        MovingSphere {
+            center0,
+            center1,
+            time0,
+            time1,
+            radius,
+            material,
+        }
+    }
+
+    /// The center of the sphere at time `t`, interpolated between `center0` and `center1`.
+    pub fn center(&self, t: f64) -> Point3 {
+        self.center0
+            + ((t - self.time0) / (self.time1 - self.time0)) * (self.center1 - self.center0)
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let center = self.center(ray.time);
+        let oc = ray.origin - center;
+        let a = ray.direction.magnitude_squared();
+        let half_b = oc.dot(&ray.direction);
+        let c = oc.magnitude_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return false;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || t_max < root {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || t_max < root {
+                return false;
+            }
+        }
+
+        record.t = root;
+        record.point = ray.at(root);
+        let outward_normal = (record.point - center) / self.radius;
+        record.set_face_normal(ray, outward_normal);
+        record.material = Some(Arc::clone(&self.material));
+
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let radius = Vec3(self.radius, self.radius, self.radius);
+        let box0 = Aabb::new(
+            self.center(self.time0) - radius,
+            self.center(self.time0) + radius,
+        );
+        let box1 = Aabb::new(
+            self.center(self.time1) - radius,
+            self.center(self.time1) + radius,
+        );
+        Some(surrounding_box(&box0, &box1))
+    }
+}
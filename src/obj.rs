@@ -0,0 +1,72 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::hittable_list::HittableList;
+use crate::material::Material;
+use crate::triangle::Triangle;
+use crate::vec3::Point3;
+
+/// Loads a Wavefront `.obj` file into a [`HittableList`] of [`Triangle`]s, all sharing
+/// `material`. Only vertex (`v`) and face (`f`) records are consulted; polygon faces are
+/// triangulated as a fan.
+pub fn load_obj<P: AsRef<Path>>(
+    path: P,
+    material: Arc<dyn Material + Send + Sync>,
+) -> io::Result<HittableList> {
+    let contents = fs::read_to_string(path)?;
+    let mut vertices: Vec<Point3> = Vec::new();
+    let mut mesh = HittableList::default();
+
+    for line in contents.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let coords: Vec<f64> =
+                    tokens.filter_map(|t| t.parse().ok()).take(3).collect();
+                if let [x, y, z] = coords[..] {
+                    vertices.push(Point3(x, y, z));
+                }
+            }
+            Some("f") => {
+                let indices: Vec<usize> = tokens
+                    .filter_map(|t| parse_face_index(t, vertices.len()))
+                    .collect();
+                // Fan-triangulate the polygon around its first vertex.
+                for i in 1..indices.len().saturating_sub(1) {
+                    mesh.add(Triangle::new(
+                        vertices[indices[0]],
+                        vertices[indices[i]],
+                        vertices[indices[i + 1]],
+                        Arc::clone(&material),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(mesh)
+}
+
+/// Parses a face vertex reference (`v`, `v/vt`, `v/vt/vn`, or `v//vn`) into a 0-based
+/// vertex index, resolving negative (relative) indices against `vertex_count`. Returns
+/// `None` for indices that fall outside the vertices seen so far, rather than letting a
+/// later lookup panic.
+fn parse_face_index(token: &str, vertex_count: usize) -> Option<usize> {
+    let raw: i64 = token.split('/').next()?.parse().ok()?;
+    let index = if raw > 0 {
+        raw - 1
+    } else if raw < 0 {
+        vertex_count as i64 + raw
+    } else {
+        return None;
+    };
+
+    if (0..vertex_count as i64).contains(&index) {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
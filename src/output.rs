@@ -0,0 +1,123 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use image::{ImageBuffer, Rgb};
+
+use crate::color::Color;
+use crate::utils::clamp;
+
+/// Converts an accumulated pixel color into a gamma-corrected, clamped 8-bit triple.
+/// Shared by every [`Output`] backend so the conversion lives in one place.
+pub fn to_rgb(color: Color, samples_per_pixel: usize) -> [u8; 3] {
+    let scale = 1.0 / samples_per_pixel as f64;
+    let r = (scale * color.0).sqrt();
+    let g = (scale * color.1).sqrt();
+    let b = (scale * color.2).sqrt();
+    [
+        (256.0 * clamp(r, 0.0, 0.999)) as u8,
+        (256.0 * clamp(g, 0.0, 0.999)) as u8,
+        (256.0 * clamp(b, 0.0, 0.999)) as u8,
+    ]
+}
+
+/// A rendering target: pixels are accumulated via [`Output::set_pixel`] and flushed to a
+/// file with [`Output::write`].
+pub trait Output {
+    /// Stores the accumulated color for the pixel at `(x, y)`, with `y` measured from the
+    /// bottom of the image.
+    fn set_pixel(&mut self, x: usize, y: usize, color: Color);
+
+    /// Writes the collected image to `path`.
+    fn write(&self, path: &str) -> io::Result<()>;
+}
+
+/// Shared pixel storage, indexed with `y` running from the bottom up.
+struct Framebuffer {
+    width: usize,
+    height: usize,
+    pixels: Vec<[u8; 3]>,
+}
+
+impl Framebuffer {
+    fn new(width: usize, height: usize) -> Framebuffer {
+        Framebuffer {
+            width,
+            height,
+            pixels: vec![[0, 0, 0]; width * height],
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize, rgb: [u8; 3]) {
+        self.pixels[y * self.width + x] = rgb;
+    }
+
+    fn get(&self, x: usize, y: usize) -> [u8; 3] {
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// A plain-text PPM (P3) backend, preserving the renderer's original output format.
+pub struct Ppm {
+    buffer: Framebuffer,
+    samples_per_pixel: usize,
+}
+
+impl Ppm {
+    pub fn new(width: usize, height: usize, samples_per_pixel: usize) -> Ppm {
+        Ppm {
+            buffer: Framebuffer::new(width, height),
+            samples_per_pixel,
+        }
+    }
+}
+
+impl Output for Ppm {
+    fn set_pixel(&mut self, x: usize, y: usize, color: Color) {
+        self.buffer.set(x, y, to_rgb(color, self.samples_per_pixel));
+    }
+
+    fn write(&self, path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "P3\n{} {}\n255", self.buffer.width, self.buffer.height)?;
+        for y in (0..self.buffer.height).rev() {
+            for x in 0..self.buffer.width {
+                let [r, g, b] = self.buffer.get(x, y);
+                writeln!(writer, "{r} {g} {b}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A PNG backend that writes a real image file.
+pub struct Png {
+    buffer: Framebuffer,
+    samples_per_pixel: usize,
+}
+
+impl Png {
+    pub fn new(width: usize, height: usize, samples_per_pixel: usize) -> Png {
+        Png {
+            buffer: Framebuffer::new(width, height),
+            samples_per_pixel,
+        }
+    }
+}
+
+impl Output for Png {
+    fn set_pixel(&mut self, x: usize, y: usize, color: Color) {
+        self.buffer.set(x, y, to_rgb(color, self.samples_per_pixel));
+    }
+
+    fn write(&self, path: &str) -> io::Result<()> {
+        let mut image = ImageBuffer::new(self.buffer.width as u32, self.buffer.height as u32);
+        for y in 0..self.buffer.height {
+            for x in 0..self.buffer.width {
+                // PNG rows run top-to-bottom, so flip the bottom-up framebuffer.
+                let row = self.buffer.height - 1 - y;
+                image.put_pixel(x as u32, row as u32, Rgb(self.buffer.get(x, y)));
+            }
+        }
+        image.save(path).map_err(io::Error::other)
+    }
+}
@@ -0,0 +1,106 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::vec3::{Point3, Vec3};
+
+const POINT_COUNT: usize = 256;
+
+/// Perlin noise over random unit gradient vectors, hashed through three permutation tables.
+pub struct Perlin {
+    random_vectors: Vec<Vec3>,
+    perm_x: Vec<usize>,
+    perm_y: Vec<usize>,
+    perm_z: Vec<usize>,
+}
+
+impl Perlin {
+    pub fn new() -> Perlin {
+        let mut rng = rand::thread_rng();
+        let random_vectors = (0..POINT_COUNT)
+            .map(|_| Vec3::random_range(-1.0, 1.0).normalized())
+            .collect();
+
+        Perlin {
+            random_vectors,
+            perm_x: generate_perm(&mut rng),
+            perm_y: generate_perm(&mut rng),
+            perm_z: generate_perm(&mut rng),
+        }
+    }
+
+    /// Evaluates the noise field at `p`, returning a value in roughly `[-1, 1]`.
+    pub fn noise(&self, p: &Point3) -> f64 {
+        let u = p.x() - p.x().floor();
+        let v = p.y() - p.y().floor();
+        let w = p.z() - p.z().floor();
+
+        let i = p.x().floor() as i64;
+        let j = p.y().floor() as i64;
+        let k = p.z().floor() as i64;
+
+        let mut corners = [[[Vec3::default(); 2]; 2]; 2];
+        for (di, plane) in corners.iter_mut().enumerate() {
+            for (dj, row) in plane.iter_mut().enumerate() {
+                for (dk, corner) in row.iter_mut().enumerate() {
+                    let hash = self.perm_x[((i + di as i64) & 255) as usize]
+                        ^ self.perm_y[((j + dj as i64) & 255) as usize]
+                        ^ self.perm_z[((k + dk as i64) & 255) as usize];
+                    *corner = self.random_vectors[hash];
+                }
+            }
+        }
+
+        perlin_interp(&corners, u, v, w)
+    }
+
+    /// Summed absolute noise over `depth` octaves, each with half the weight and double
+    /// the frequency of the last.
+    pub fn turbulence(&self, p: &Point3, depth: usize) -> f64 {
+        let mut accum = 0.0;
+        let mut temp_p = *p;
+        let mut weight = 1.0;
+
+        for _ in 0..depth {
+            accum += weight * self.noise(&temp_p).abs();
+            weight *= 0.5;
+            temp_p *= 2.0;
+        }
+
+        accum
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Perlin {
+        Perlin::new()
+    }
+}
+
+fn generate_perm<R: Rng + ?Sized>(rng: &mut R) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..POINT_COUNT).collect();
+    perm.shuffle(rng);
+    perm
+}
+
+/// Trilinear interpolation of the eight corner gradients, with Hermite-smoothed weights.
+fn perlin_interp(corners: &[[[Vec3; 2]; 2]; 2], u: f64, v: f64, w: f64) -> f64 {
+    let uu = u * u * (3.0 - 2.0 * u);
+    let vv = v * v * (3.0 - 2.0 * v);
+    let ww = w * w * (3.0 - 2.0 * w);
+
+    let mut accum = 0.0;
+    for (i, plane) in corners.iter().enumerate() {
+        for (j, row) in plane.iter().enumerate() {
+            for (k, corner) in row.iter().enumerate() {
+                let (fi, fj, fk) = (i as f64, j as f64, k as f64);
+                let weight = Vec3(u - fi, v - fj, w - fk);
+                accum += (fi * uu + (1.0 - fi) * (1.0 - uu))
+                    * (fj * vv + (1.0 - fj) * (1.0 - vv))
+                    * (fk * ww + (1.0 - fk) * (1.0 - ww))
+                    * corner.dot(&weight);
+            }
+        }
+    }
+
+    accum
+}
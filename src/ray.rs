@@ -0,0 +1,25 @@
+use crate::vec3::{Point3, Vec3};
+
+/// A ray with an origin and a direction, parameterized as `origin + t * direction`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ray {
+    pub origin: Point3,
+    pub direction: Vec3,
+    /// The instant within the shutter interval at which this ray is cast.
+    pub time: f64,
+}
+
+impl Ray {
+    pub fn new(origin: Point3, direction: Vec3, time: f64) -> Ray {
+        Ray {
+            origin,
+            direction,
+            time,
+        }
+    }
+
+    /// The point reached after traveling `t` along the ray.
+    pub fn at(&self, t: f64) -> Point3 {
+        self.origin + t * self.direction
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::hittable_list::HittableList;
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{Point3, Vec3};
+
+/// The plane a [`Rect2D`] lies in, naming the two in-plane axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plane {
+    XY,
+    XZ,
+    YZ,
+}
+
+impl Plane {
+    /// The two axis indices spanned by the plane.
+    fn in_plane_axes(self) -> (usize, usize) {
+        match self {
+            Plane::XY => (0, 1),
+            Plane::XZ => (0, 2),
+            Plane::YZ => (1, 2),
+        }
+    }
+
+    /// The axis index held fixed by the plane.
+    fn fixed_axis(self) -> usize {
+        match self {
+            Plane::XY => 2,
+            Plane::XZ => 1,
+            Plane::YZ => 0,
+        }
+    }
+}
+
+/// An axis-aligned rectangle lying in one of the coordinate planes.
+pub struct Rect2D {
+    plane: Plane,
+    a0_min: f64,
+    a0_max: f64,
+    a1_min: f64,
+    a1_max: f64,
+    k: f64,
+    material: Arc<dyn Material + Send + Sync>,
+}
+
+impl Rect2D {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        plane: Plane,
+        a0_min: f64,
+        a0_max: f64,
+        a1_min: f64,
+        a1_max: f64,
+        k: f64,
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> Rect2D {
+        Rect2D {
+            plane,
+            a0_min,
+            a0_max,
+            a1_min,
+            a1_max,
+            k,
+            material,
+        }
+    }
+}
+
+impl Hittable for Rect2D {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let fixed = self.plane.fixed_axis();
+        let t = (self.k - ray.origin[fixed]) / ray.direction[fixed];
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        let (ax0, ax1) = self.plane.in_plane_axes();
+        let p0 = ray.origin[ax0] + t * ray.direction[ax0];
+        let p1 = ray.origin[ax1] + t * ray.direction[ax1];
+        if p0 < self.a0_min || p0 > self.a0_max || p1 < self.a1_min || p1 > self.a1_max {
+            return false;
+        }
+
+        record.u = (p0 - self.a0_min) / (self.a0_max - self.a0_min);
+        record.v = (p1 - self.a1_min) / (self.a1_max - self.a1_min);
+        record.t = t;
+        let mut outward_normal = Vec3::default();
+        outward_normal[fixed] = 1.0;
+        record.set_face_normal(ray, outward_normal);
+        record.material = Some(Arc::clone(&self.material));
+        record.point = ray.at(t);
+
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        // The rectangle has no thickness along its fixed axis, so pad it a little to
+        // give the box a non-zero extent in every dimension.
+        let fixed = self.plane.fixed_axis();
+        let (ax0, ax1) = self.plane.in_plane_axes();
+        let mut min = Point3::default();
+        let mut max = Point3::default();
+        min[ax0] = self.a0_min;
+        max[ax0] = self.a0_max;
+        min[ax1] = self.a1_min;
+        max[ax1] = self.a1_max;
+        min[fixed] = self.k - 0.0001;
+        max[fixed] = self.k + 0.0001;
+        Some(Aabb::new(min, max))
+    }
+}
+
+/// An axis-aligned box built from its six rectangular faces.
+pub struct Cuboid {
+    box_min: Point3,
+    box_max: Point3,
+    sides: HittableList,
+}
+
+impl Cuboid {
+    pub fn new(
+        p0: Point3,
+        p1: Point3,
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> Cuboid {
+        let mut sides = HittableList::default();
+
+        sides.add(Rect2D::new(
+            Plane::XY, p0.x(), p1.x(), p0.y(), p1.y(), p1.z(), Arc::clone(&material),
+        ));
+        sides.add(Rect2D::new(
+            Plane::XY, p0.x(), p1.x(), p0.y(), p1.y(), p0.z(), Arc::clone(&material),
+        ));
+        sides.add(Rect2D::new(
+            Plane::XZ, p0.x(), p1.x(), p0.z(), p1.z(), p1.y(), Arc::clone(&material),
+        ));
+        sides.add(Rect2D::new(
+            Plane::XZ, p0.x(), p1.x(), p0.z(), p1.z(), p0.y(), Arc::clone(&material),
+        ));
+        sides.add(Rect2D::new(
+            Plane::YZ, p0.y(), p1.y(), p0.z(), p1.z(), p1.x(), Arc::clone(&material),
+        ));
+        sides.add(Rect2D::new(
+            Plane::YZ, p0.y(), p1.y(), p0.z(), p1.z(), p0.x(), material,
+        ));
+
+        Cuboid {
+            box_min: p0,
+            box_max: p1,
+            sides,
+        }
+    }
+}
+
+impl Hittable for Cuboid {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        self.sides.hit(ray, t_min, t_max, record)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(Aabb::new(self.box_min, self.box_max))
+    }
+}
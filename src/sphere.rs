@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::utils::PI;
+use crate::vec3::{Point3, Vec3};
+
+/// A sphere defined by a center, a radius and a surface material.
+pub struct Sphere {
+    pub center: Point3,
+    pub radius: f64,
+    pub material: Arc<dyn Material + Send + Sync>,
+}
+
+impl Sphere {
+    pub fn new(
+        center: Point3,
+        radius: f64,
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> Sphere {
+        Sphere {
+            center,
+            radius,
+            material,
+        }
+    }
+
+    /// Maps a point on the unit sphere to texture coordinates `(u, v)` in `[0, 1]` from
+    /// its spherical angles.
+    fn get_uv(outward_normal: &Vec3) -> (f64, f64) {
+        let theta = (-outward_normal.y()).acos();
+        let phi = (-outward_normal.z()).atan2(outward_normal.x()) + PI;
+        (phi / (2.0 * PI), theta / PI)
+    }
+}
+
+impl Hittable for Sphere {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        let oc = ray.origin - self.center;
+        let a = ray.direction.magnitude_squared();
+        let half_b = oc.dot(&ray.direction);
+        let c = oc.magnitude_squared() - self.radius * self.radius;
+
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return false;
+        }
+        let sqrtd = discriminant.sqrt();
+
+        // Find the nearest root lying within the acceptable range.
+        let mut root = (-half_b - sqrtd) / a;
+        if root < t_min || t_max < root {
+            root = (-half_b + sqrtd) / a;
+            if root < t_min || t_max < root {
+                return false;
+            }
+        }
+
+        record.t = root;
+        record.point = ray.at(root);
+        let outward_normal = (record.point - self.center) / self.radius;
+        record.set_face_normal(ray, outward_normal);
+        let (u, v) = Sphere::get_uv(&outward_normal);
+        record.u = u;
+        record.v = v;
+        record.material = Some(Arc::clone(&self.material));
+
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let radius = Vec3(self.radius, self.radius, self.radius);
+        Some(Aabb::new(self.center - radius, self.center + radius))
+    }
+}
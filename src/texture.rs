@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::perlin::Perlin;
+use crate::vec3::Point3;
+
+/// A mapping from a surface coordinate (and world position) to a color.
+pub trait Texture {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color;
+}
+
+/// A texture that is the same color everywhere.
+pub struct SolidColor {
+    pub color: Color,
+}
+
+impl SolidColor {
+    pub fn new(color: &Color) -> SolidColor {
+        SolidColor { color: *color }
+    }
+}
+
+impl Texture for SolidColor {
+    fn value(&self, _u: f64, _v: f64, _p: &Point3) -> Color {
+        self.color
+    }
+}
+
+/// A 3D checker pattern alternating between two sub-textures.
+pub struct CheckerTexture {
+    pub scale: f64,
+    pub even: Arc<dyn Texture + Send + Sync>,
+    pub odd: Arc<dyn Texture + Send + Sync>,
+}
+
+impl CheckerTexture {
+    pub fn new(
+        scale: f64,
+        even: Arc<dyn Texture + Send + Sync>,
+        odd: Arc<dyn Texture + Send + Sync>,
+    ) -> CheckerTexture {
+        CheckerTexture { scale, even, odd }
+    }
+
+    /// Convenience constructor for a checker of two solid colors.
+    pub fn from_colors(scale: f64, c1: &Color, c2: &Color) -> CheckerTexture {
+        CheckerTexture::new(
+            scale,
+            Arc::new(SolidColor::new(c1)),
+            Arc::new(SolidColor::new(c2)),
+        )
+    }
+}
+
+impl Texture for CheckerTexture {
+    fn value(&self, u: f64, v: f64, p: &Point3) -> Color {
+        let sines =
+            (self.scale * p.x()).sin() * (self.scale * p.y()).sin() * (self.scale * p.z()).sin();
+        if sines < 0.0 {
+            self.odd.value(u, v, p)
+        } else {
+            self.even.value(u, v, p)
+        }
+    }
+}
+
+/// A Perlin-noise texture, scaled to control feature size. Uses turbulence to produce a
+/// marble-like banding.
+pub struct NoiseTexture {
+    pub noise: Perlin,
+    pub scale: f64,
+}
+
+impl NoiseTexture {
+    pub fn new(scale: f64) -> NoiseTexture {
+        NoiseTexture {
+            noise: Perlin::new(),
+            scale,
+        }
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn value(&self, _u: f64, _v: f64, p: &Point3) -> Color {
+        let marble = 1.0 + (self.scale * p.z() + 10.0 * self.noise.turbulence(p, 7)).sin();
+        Color(0.5, 0.5, 0.5) * marble
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::aabb::Aabb;
+use crate::hittable::{HitRecord, Hittable};
+use crate::material::Material;
+use crate::ray::Ray;
+use crate::vec3::{Point3, Vec3};
+
+const EPSILON: f64 = 1e-8;
+
+/// A single triangle, optionally carrying per-vertex normals for smooth shading.
+pub struct Triangle {
+    v0: Point3,
+    v1: Point3,
+    v2: Point3,
+    normals: Option<[Vec3; 3]>,
+    material: Arc<dyn Material + Send + Sync>,
+}
+
+impl Triangle {
+    pub fn new(
+        v0: Point3,
+        v1: Point3,
+        v2: Point3,
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> Triangle {
+        Triangle {
+            v0,
+            v1,
+            v2,
+            normals: None,
+            material,
+        }
+    }
+
+    /// Builds a triangle with per-vertex normals, interpolated across the surface.
+    pub fn with_normals(
+        v0: Point3,
+        v1: Point3,
+        v2: Point3,
+        normals: [Vec3; 3],
+        material: Arc<dyn Material + Send + Sync>,
+    ) -> Triangle {
+        Triangle {
+            v0,
+            v1,
+            v2,
+            normals: Some(normals),
+            material,
+        }
+    }
+}
+
+impl Hittable for Triangle {
+    fn hit(&self, ray: &Ray, t_min: f64, t_max: f64, record: &mut HitRecord) -> bool {
+        // Möller–Trumbore intersection.
+        let e1 = self.v1 - self.v0;
+        let e2 = self.v2 - self.v0;
+        let pvec = ray.direction.cross(&e2);
+        let det = e1.dot(&pvec);
+        if det.abs() < EPSILON {
+            return false;
+        }
+
+        let inv_det = 1.0 / det;
+        let tvec = ray.origin - self.v0;
+        let u = tvec.dot(&pvec) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return false;
+        }
+
+        let qvec = tvec.cross(&e1);
+        let v = ray.direction.dot(&qvec) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return false;
+        }
+
+        let t = e2.dot(&qvec) * inv_det;
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        record.t = t;
+        record.point = ray.at(t);
+        record.u = u;
+        record.v = v;
+        let outward_normal = match self.normals {
+            Some([n0, n1, n2]) => (1.0 - u - v) * n0 + u * n1 + v * n2,
+            None => e1.cross(&e2),
+        }
+        .normalized();
+        record.set_face_normal(ray, outward_normal);
+        record.material = Some(Arc::clone(&self.material));
+
+        true
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let mut min = Point3::default();
+        let mut max = Point3::default();
+        for axis in 0..3 {
+            let lo = self.v0[axis].min(self.v1[axis]).min(self.v2[axis]);
+            let hi = self.v0[axis].max(self.v1[axis]).max(self.v2[axis]);
+            // Pad degenerate (axis-aligned) extents so the box has thickness everywhere.
+            min[axis] = lo - EPSILON;
+            max[axis] = hi + EPSILON;
+        }
+        Some(Aabb::new(min, max))
+    }
+}